@@ -3,6 +3,8 @@ use slog::Logger;
 use {Result, Build, LoggerBuilder};
 use file::FileLoggerConfig;
 use null::NullLoggerConfig;
+#[cfg(feature = "syslog")]
+use syslog::SyslogLoggerConfig;
 use terminal::TerminalLoggerConfig;
 
 /// Configuration of a logger builder.
@@ -85,6 +87,10 @@ pub enum LoggerConfig {
     #[serde(rename = "null")]
     Null(NullLoggerConfig),
 
+    #[cfg(feature = "syslog")]
+    #[serde(rename = "syslog")]
+    Syslog(SyslogLoggerConfig),
+
     #[serde(rename = "terminal")]
     Terminal(TerminalLoggerConfig),
 }
@@ -94,6 +100,8 @@ impl Config for LoggerConfig {
         match *self {
             LoggerConfig::File(ref c) => track!(c.try_to_builder()).map(LoggerBuilder::File),
             LoggerConfig::Null(ref c) => track!(c.try_to_builder()).map(LoggerBuilder::Null),
+            #[cfg(feature = "syslog")]
+            LoggerConfig::Syslog(ref c) => track!(c.try_to_builder()).map(LoggerBuilder::Syslog),
             LoggerConfig::Terminal(ref c) => {
                 track!(c.try_to_builder()).map(LoggerBuilder::Terminal)
             }
@@ -1,15 +1,24 @@
 //! File logger.
 use std::fmt::Debug;
-use std::fs::{File, OpenOptions};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+#[cfg(feature = "compress")]
+use flate2::write::GzEncoder;
+#[cfg(feature = "compress")]
+use flate2::Compression;
 use slog::{Drain, FnValue, Logger};
 use slog_async::Async;
 use slog_term::{CompactFormat, FullFormat, PlainDecorator};
+#[cfg(feature = "json")]
+use slog_json::Json;
+
+use trackable::error::ErrorKindExt;
 
 use {Build, Config, Result};
+use error::ErrorKind;
 use misc::{module_and_line, timezone_to_timestamp_fn};
-use types::{Format, Severity, TimeZone};
+use types::{Format, IfExists, ModuleLevelFilter, ModuleLevels, Severity, TimeZone};
 
 /// A logger builder which build loggers that write log records to the specified file.
 ///
@@ -21,6 +30,8 @@ pub struct FileLoggerBuilder {
     level: Severity,
     appender: FileAppender,
     channel_size: usize,
+    if_exists: IfExists,
+    module_levels: ModuleLevels,
 }
 impl FileLoggerBuilder {
     /// Makes a new `FileLoggerBuilder` instance.
@@ -34,6 +45,8 @@ impl FileLoggerBuilder {
             level: Severity::default(),
             appender: FileAppender::new(path),
             channel_size: 1024,
+            if_exists: IfExists::default(),
+            module_levels: ModuleLevels::default(),
         }
     }
 
@@ -61,6 +74,56 @@ impl FileLoggerBuilder {
         self
     }
 
+    /// Sets the maximum size (in bytes) of the log file before it is rotated.
+    ///
+    /// The default value is `None` (i.e., rotation is disabled).
+    ///
+    /// Note that the default `rotate_keep` is `0`: unless you also raise it,
+    /// hitting this threshold deletes the current log file outright instead
+    /// of archiving it to `file.log.1`.
+    pub fn rotate_size(&mut self, rotate_size: u64) -> &mut Self {
+        self.appender.rotate_size = Some(rotate_size);
+        self
+    }
+
+    /// Sets the maximum number of rotated log files to keep.
+    ///
+    /// The default value is `0`, meaning that when `rotate_size` is crossed
+    /// the current log file is simply deleted rather than archived to
+    /// `file.log.1`. Set this to `1` or higher to retain rotated backups.
+    pub fn rotate_keep(&mut self, rotate_keep: usize) -> &mut Self {
+        self.appender.rotate_keep = rotate_keep;
+        self
+    }
+
+    /// Sets whether rotated log files are compressed with gzip.
+    ///
+    /// The default value is `false`.
+    #[cfg(feature = "compress")]
+    pub fn rotate_compress(&mut self, rotate_compress: bool) -> &mut Self {
+        self.appender.rotate_compress = rotate_compress;
+        self
+    }
+
+    /// Sets the policy for handling the situation where the destination log file
+    /// already exists.
+    ///
+    /// The default value is `IfExists::Append`.
+    pub fn if_exists(&mut self, if_exists: IfExists) -> &mut Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    /// Sets the per-module log level specification of this logger.
+    ///
+    /// See [`ModuleLevels`] for the textual syntax it is parsed from.
+    ///
+    /// [`ModuleLevels`]: ../types/struct.ModuleLevels.html
+    pub fn module_levels(&mut self, module_levels: ModuleLevels) -> &mut Self {
+        self.module_levels = module_levels;
+        self
+    }
+
     fn build_with_drain<D>(&self, drain: D) -> Logger
     where
         D: Drain + Send + 'static,
@@ -70,12 +133,13 @@ impl FileLoggerBuilder {
             .chan_size(self.channel_size)
             .build()
             .fuse();
-        let drain = self.level.set_level_filter(drain).fuse();
+        let drain = ModuleLevelFilter::new(drain, self.module_levels.clone(), self.level).fuse();
         Logger::root(drain, o!("module" => FnValue(module_and_line)))
     }
 }
 impl Build for FileLoggerBuilder {
     fn build(&self) -> Result<Logger> {
+        track!(self.prepare_file())?;
         let decorator = PlainDecorator::new(self.appender.clone());
         let timestamp = timezone_to_timestamp_fn(self.timezone);
         let logger = match self.format {
@@ -87,21 +151,68 @@ impl Build for FileLoggerBuilder {
                 let format = CompactFormat::new(decorator).use_custom_timestamp(timestamp);
                 self.build_with_drain(format.build())
             }
+            #[cfg(feature = "json")]
+            Format::Json => {
+                let format = Json::new(self.appender.clone())
+                    .add_default_keys()
+                    .set_newlines(true)
+                    .build();
+                self.build_with_drain(format)
+            }
         };
         Ok(logger)
     }
 }
+impl FileLoggerBuilder {
+    fn prepare_file(&self) -> Result<()> {
+        match self.if_exists {
+            IfExists::Append => Ok(()),
+            IfExists::Truncate => {
+                OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .truncate(true)
+                    .open(&self.appender.path)?;
+                Ok(())
+            }
+            IfExists::Fail => {
+                let exists_and_non_empty = fs::metadata(&self.appender.path)
+                    .map(|m| m.len() > 0)
+                    .unwrap_or(false);
+                if exists_and_non_empty {
+                    return Err(ErrorKind::Invalid
+                        .cause(format!(
+                            "the log file {:?} already exists",
+                            self.appender.path
+                        ))
+                        .into());
+                }
+                Ok(())
+            }
+        }
+    }
+}
 
 #[derive(Debug)]
 struct FileAppender {
     path: PathBuf,
     file: Option<File>,
+    written_size: u64,
+    rotate_size: Option<u64>,
+    rotate_keep: usize,
+    #[cfg(feature = "compress")]
+    rotate_compress: bool,
 }
 impl Clone for FileAppender {
     fn clone(&self) -> Self {
         FileAppender {
             path: self.path.clone(),
             file: None,
+            written_size: 0,
+            rotate_size: self.rotate_size,
+            rotate_keep: self.rotate_keep,
+            #[cfg(feature = "compress")]
+            rotate_compress: self.rotate_compress,
         }
     }
 }
@@ -110,6 +221,11 @@ impl FileAppender {
         FileAppender {
             path: path.as_ref().to_path_buf(),
             file: None,
+            written_size: 0,
+            rotate_size: None,
+            rotate_keep: 0,
+            #[cfg(feature = "compress")]
+            rotate_compress: false,
         }
     }
     fn reopen_if_needed(&mut self) -> io::Result<()> {
@@ -119,22 +235,93 @@ impl FileAppender {
                 .append(true)
                 .write(true)
                 .open(&self.path)?;
+            self.written_size = file.metadata()?.len();
             self.file = Some(file);
         }
         Ok(())
     }
+    fn rotate(&mut self) -> io::Result<()> {
+        if let Some(mut f) = self.file.take() {
+            f.flush()?;
+        }
+
+        if self.rotate_keep > 0 {
+            let oldest = self.rotated_name(self.rotate_keep);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+            for i in (1..self.rotate_keep).rev() {
+                let from = self.rotated_name(i);
+                if from.exists() {
+                    fs::rename(&from, self.rotated_name(i + 1))?;
+                }
+            }
+            let dest = self.rotated_name(1);
+            self.archive(&dest)?;
+        } else {
+            fs::remove_file(&self.path)?;
+        }
+        self.written_size = 0;
+        Ok(())
+    }
+
+    /// Returns the path of the `i`-th rotated log file, using a `.gz` suffix iff
+    /// gzip compression is both enabled (`compress` feature) and requested
+    /// (`rotate_compress`).
+    #[cfg(feature = "compress")]
+    fn rotated_name(&self, i: usize) -> PathBuf {
+        if self.rotate_compress {
+            PathBuf::from(format!("{}.{}.gz", self.path.display(), i))
+        } else {
+            PathBuf::from(format!("{}.{}", self.path.display(), i))
+        }
+    }
+
+    #[cfg(not(feature = "compress"))]
+    fn rotated_name(&self, i: usize) -> PathBuf {
+        PathBuf::from(format!("{}.{}", self.path.display(), i))
+    }
+
+    /// Moves the current log file to `dest`, compressing it on the fly if
+    /// `rotate_compress` is enabled.
+    #[cfg(feature = "compress")]
+    fn archive(&self, dest: &Path) -> io::Result<()> {
+        if self.rotate_compress {
+            let mut input = File::open(&self.path)?;
+            let output = File::create(dest)?;
+            let mut encoder = GzEncoder::new(output, Compression::default());
+            io::copy(&mut input, &mut encoder)?;
+            encoder.finish()?;
+            fs::remove_file(&self.path)
+        } else {
+            fs::rename(&self.path, dest)
+        }
+    }
+
+    #[cfg(not(feature = "compress"))]
+    fn archive(&self, dest: &Path) -> io::Result<()> {
+        fs::rename(&self.path, dest)
+    }
 }
 impl Write for FileAppender {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.reopen_if_needed()?;
-        if let Some(ref mut f) = self.file {
-            f.write(buf)
+        let written = if let Some(ref mut f) = self.file {
+            f.write(buf)?
         } else {
-            Err(io::Error::new(
+            return Err(io::Error::new(
                 io::ErrorKind::Other,
                 format!("Cannot open file: {:?}", self.path),
-            ))
+            ));
+        };
+        self.written_size += written as u64;
+
+        if let Some(rotate_size) = self.rotate_size {
+            if self.written_size >= rotate_size {
+                self.rotate()?;
+            }
         }
+        Ok(written)
     }
     fn flush(&mut self) -> io::Result<()> {
         if let Some(ref mut f) = self.file {
@@ -165,6 +352,45 @@ pub struct FileLoggerConfig {
     /// Asynchronous channel size
     #[serde(default = "default_channel_size")]
     pub channel_size: usize,
+
+    /// Log file size (in bytes) at which the file will be rotated.
+    ///
+    /// If this is `None` (the default), the log file is never rotated. Note
+    /// that the default `rotate_keep` is `0`: unless you also raise it,
+    /// hitting this threshold deletes the current log file outright instead
+    /// of archiving it to `file.log.1`.
+    #[serde(default)]
+    pub rotate_size: Option<u64>,
+
+    /// Maximum number of rotated log files to keep.
+    ///
+    /// The default value is `0`, meaning that when `rotate_size` is crossed
+    /// the current log file is simply deleted rather than archived to
+    /// `file.log.1`. Set this to `1` or higher to retain rotated backups.
+    #[serde(default)]
+    pub rotate_keep: usize,
+
+    /// Whether to compress rotated log files with gzip.
+    ///
+    /// The default value is `false`.
+    #[cfg(feature = "compress")]
+    #[serde(default)]
+    pub rotate_compress: bool,
+
+    /// The policy for handling the situation where the destination log file already
+    /// exists.
+    ///
+    /// The default value is `IfExists::Append`.
+    #[serde(default)]
+    pub if_exists: IfExists,
+
+    /// Per-module log level specification.
+    ///
+    /// See [`ModuleLevels`] for the syntax.
+    ///
+    /// [`ModuleLevels`]: ../types/struct.ModuleLevels.html
+    #[serde(default)]
+    pub module_levels: Option<String>,
 }
 impl Config for FileLoggerConfig {
     type Builder = FileLoggerBuilder;
@@ -174,6 +400,16 @@ impl Config for FileLoggerConfig {
         builder.format(self.format);
         builder.timezone(self.timezone);
         builder.channel_size(self.channel_size);
+        if let Some(rotate_size) = self.rotate_size {
+            builder.rotate_size(rotate_size);
+        }
+        builder.rotate_keep(self.rotate_keep);
+        #[cfg(feature = "compress")]
+        builder.rotate_compress(self.rotate_compress);
+        builder.if_exists(self.if_exists);
+        if let Some(ref spec) = self.module_levels {
+            builder.module_levels(track!(spec.parse())?);
+        }
         Ok(builder)
     }
 }
@@ -181,3 +417,53 @@ impl Config for FileLoggerConfig {
 fn default_channel_size() -> usize {
     1024
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = ::std::env::temp_dir().join(format!(
+            "sloggers-test-{}-{}",
+            ::std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn rotate_shifts_backups_and_drops_the_oldest() {
+        let dir = scratch_dir("rotate-shift");
+        let path = dir.join("file.log");
+        fs::write(&path, b"live").unwrap();
+        fs::write(format!("{}.1", path.display()), b"one").unwrap();
+        fs::write(format!("{}.2", path.display()), b"two").unwrap();
+
+        let mut appender = FileAppender::new(&path);
+        appender.rotate_keep = 2;
+        appender.rotate().unwrap();
+
+        assert!(!path.exists());
+        assert_eq!(fs::read(format!("{}.1", path.display())).unwrap(), b"live");
+        assert_eq!(fs::read(format!("{}.2", path.display())).unwrap(), b"one");
+        assert!(!PathBuf::from(format!("{}.3", path.display())).exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rotate_with_zero_keep_deletes_the_log() {
+        let dir = scratch_dir("rotate-delete");
+        let path = dir.join("file.log");
+        fs::write(&path, b"live").unwrap();
+
+        let mut appender = FileAppender::new(&path);
+        appender.rotate().unwrap();
+
+        assert!(!path.exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,65 @@
+//! A `slog`-based logger library for easily building a `Drain` by composing ready-made
+//! components.
+#![warn(missing_docs)]
+#[macro_use]
+extern crate slog;
+extern crate slog_async;
+extern crate slog_term;
+#[cfg(feature = "json")]
+extern crate slog_json;
+#[cfg(feature = "compress")]
+extern crate flate2;
+#[cfg(feature = "syslog")]
+extern crate syslog;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate trackable;
+extern crate log;
+
+use slog::Logger;
+
+pub use config::{Config, LoggerConfig};
+pub use error::{Error, ErrorKind};
+
+pub mod config;
+pub mod error;
+pub mod file;
+pub mod null;
+#[cfg(feature = "syslog")]
+pub mod syslog;
+pub mod terminal;
+pub mod types;
+
+mod misc;
+
+/// This crate specific `Result` type.
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+/// This trait allows to build a `slog::Logger` instance.
+pub trait Build {
+    /// Builds a `Logger` with the given settings.
+    fn build(&self) -> Result<Logger>;
+}
+
+/// The built-in logger builders.
+#[allow(missing_docs)]
+#[derive(Debug)]
+pub enum LoggerBuilder {
+    File(file::FileLoggerBuilder),
+    Null(null::NullLoggerBuilder),
+    #[cfg(feature = "syslog")]
+    Syslog(syslog::SyslogLoggerBuilder),
+    Terminal(terminal::TerminalLoggerBuilder),
+}
+impl Build for LoggerBuilder {
+    fn build(&self) -> Result<Logger> {
+        match *self {
+            LoggerBuilder::File(ref b) => track!(b.build()),
+            LoggerBuilder::Null(ref b) => track!(b.build()),
+            #[cfg(feature = "syslog")]
+            LoggerBuilder::Syslog(ref b) => track!(b.build()),
+            LoggerBuilder::Terminal(ref b) => track!(b.build()),
+        }
+    }
+}
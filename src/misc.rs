@@ -0,0 +1,15 @@
+use slog::Record;
+use slog_term::{timestamp_local, timestamp_utc, TimestampFn};
+
+use types::TimeZone;
+
+pub fn module_and_line(record: &Record) -> String {
+    format!("{}:{}", record.module(), record.line())
+}
+
+pub fn timezone_to_timestamp_fn(timezone: TimeZone) -> Box<TimestampFn> {
+    match timezone {
+        TimeZone::Utc => Box::new(timestamp_utc),
+        TimeZone::Local => Box::new(timestamp_local),
+    }
+}
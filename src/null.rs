@@ -0,0 +1,29 @@
+//! Null logger.
+use slog::{Discard, Logger};
+
+use {Build, Config, Result};
+
+/// A logger builder which build loggers that discard all log records.
+#[derive(Debug)]
+pub struct NullLoggerBuilder;
+impl NullLoggerBuilder {
+    /// Makes a new `NullLoggerBuilder` instance.
+    pub fn new() -> Self {
+        NullLoggerBuilder
+    }
+}
+impl Build for NullLoggerBuilder {
+    fn build(&self) -> Result<Logger> {
+        Ok(Logger::root(Discard, o!()))
+    }
+}
+
+/// The configuration of `NullLoggerBuilder`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NullLoggerConfig {}
+impl Config for NullLoggerConfig {
+    type Builder = NullLoggerBuilder;
+    fn try_to_builder(&self) -> Result<Self::Builder> {
+        Ok(NullLoggerBuilder::new())
+    }
+}
@@ -0,0 +1,249 @@
+//! Syslog logger.
+use std::io;
+use std::sync::Mutex;
+use slog::{Drain, FnValue, Level, Logger, OwnedKVList, Record};
+use slog_async::Async;
+use syslog::{Facility as SyslogFacility, Formatter3164, LoggerBackend, Severity as SyslogSeverity};
+
+use trackable::error::ErrorKindExt;
+
+use {Build, Config, Result};
+use error::{Error, ErrorKind};
+use misc::module_and_line;
+use types::{ModuleLevelFilter, ModuleLevels, Severity};
+
+/// A logger builder which build loggers that send log records to the local syslog daemon.
+///
+/// The resulting logger will work asynchronously (the default channel size is 1024).
+#[derive(Debug)]
+pub struct SyslogLoggerBuilder {
+    facility: Facility,
+    ident: String,
+    level: Severity,
+    channel_size: usize,
+    module_levels: ModuleLevels,
+}
+impl SyslogLoggerBuilder {
+    /// Makes a new `SyslogLoggerBuilder` instance.
+    pub fn new() -> Self {
+        SyslogLoggerBuilder {
+            facility: Facility::default(),
+            ident: default_ident(),
+            level: Severity::default(),
+            channel_size: 1024,
+            module_levels: ModuleLevels::default(),
+        }
+    }
+
+    /// Sets the syslog facility to which log records will be sent.
+    pub fn facility(&mut self, facility: Facility) -> &mut Self {
+        self.facility = facility;
+        self
+    }
+
+    /// Sets the identifier that will be attached to each log record.
+    pub fn ident<S: Into<String>>(&mut self, ident: S) -> &mut Self {
+        self.ident = ident.into();
+        self
+    }
+
+    /// Sets the log level of this logger.
+    pub fn level(&mut self, severity: Severity) -> &mut Self {
+        self.level = severity;
+        self
+    }
+
+    /// Sets the size of the asynchronous channel of this logger.
+    pub fn channel_size(&mut self, channel_size: usize) -> &mut Self {
+        self.channel_size = channel_size;
+        self
+    }
+
+    /// Sets the per-module log level specification of this logger.
+    ///
+    /// See [`ModuleLevels`] for the textual syntax it is parsed from.
+    ///
+    /// [`ModuleLevels`]: ../types/struct.ModuleLevels.html
+    pub fn module_levels(&mut self, module_levels: ModuleLevels) -> &mut Self {
+        self.module_levels = module_levels;
+        self
+    }
+}
+impl Build for SyslogLoggerBuilder {
+    fn build(&self) -> Result<Logger> {
+        let formatter = Formatter3164 {
+            facility: self.facility.to_syslog(),
+            hostname: None,
+            process: self.ident.clone(),
+            pid: 0,
+        };
+        let syslog = track!(
+            ::syslog::unix(formatter).map_err(|e| Error::from(ErrorKind::Other.cause(e.to_string())))
+        )?;
+        let drain = SyslogDrain {
+            logger: Mutex::new(syslog),
+        };
+        let drain = Async::new(drain.fuse())
+            .chan_size(self.channel_size)
+            .build()
+            .fuse();
+        let drain = ModuleLevelFilter::new(drain, self.module_levels.clone(), self.level).fuse();
+        Ok(Logger::root(drain, o!("module" => FnValue(module_and_line))))
+    }
+}
+
+struct SyslogDrain {
+    logger: Mutex<::syslog::Logger<LoggerBackend, Formatter3164>>,
+}
+impl Drain for SyslogDrain {
+    type Ok = ();
+    type Err = io::Error;
+
+    fn log(&self, record: &Record, _values: &OwnedKVList) -> io::Result<()> {
+        let message = record.msg().to_string();
+        let mut logger = self.logger.lock().expect("syslog logger mutex was poisoned");
+        match level_to_syslog(record.level()) {
+            SyslogSeverity::LOG_CRIT => logger.crit(message),
+            SyslogSeverity::LOG_ERR => logger.err(message),
+            SyslogSeverity::LOG_WARNING => logger.warning(message),
+            SyslogSeverity::LOG_INFO => logger.info(message),
+            _ => logger.debug(message),
+        }
+    }
+}
+
+fn level_to_syslog(level: Level) -> SyslogSeverity {
+    match level {
+        Level::Critical => SyslogSeverity::LOG_CRIT,
+        Level::Error => SyslogSeverity::LOG_ERR,
+        Level::Warning => SyslogSeverity::LOG_WARNING,
+        Level::Info => SyslogSeverity::LOG_INFO,
+        Level::Debug | Level::Trace => SyslogSeverity::LOG_DEBUG,
+    }
+}
+
+fn default_ident() -> String {
+    ::std::env::args()
+        .next()
+        .and_then(|arg0| {
+            ::std::path::Path::new(&arg0)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "sloggers".to_owned())
+}
+
+/// The syslog facility to which log records are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Facility {
+    /// Generic user-level messages (`LOG_USER`).
+    User,
+
+    /// System daemons (`LOG_DAEMON`).
+    Daemon,
+
+    /// Local use 0 (`LOG_LOCAL0`).
+    Local0,
+
+    /// Local use 1 (`LOG_LOCAL1`).
+    Local1,
+
+    /// Local use 2 (`LOG_LOCAL2`).
+    Local2,
+
+    /// Local use 3 (`LOG_LOCAL3`).
+    Local3,
+
+    /// Local use 4 (`LOG_LOCAL4`).
+    Local4,
+
+    /// Local use 5 (`LOG_LOCAL5`).
+    Local5,
+
+    /// Local use 6 (`LOG_LOCAL6`).
+    Local6,
+
+    /// Local use 7 (`LOG_LOCAL7`).
+    Local7,
+}
+impl Facility {
+    fn to_syslog(&self) -> SyslogFacility {
+        match *self {
+            Facility::User => SyslogFacility::LOG_USER,
+            Facility::Daemon => SyslogFacility::LOG_DAEMON,
+            Facility::Local0 => SyslogFacility::LOG_LOCAL0,
+            Facility::Local1 => SyslogFacility::LOG_LOCAL1,
+            Facility::Local2 => SyslogFacility::LOG_LOCAL2,
+            Facility::Local3 => SyslogFacility::LOG_LOCAL3,
+            Facility::Local4 => SyslogFacility::LOG_LOCAL4,
+            Facility::Local5 => SyslogFacility::LOG_LOCAL5,
+            Facility::Local6 => SyslogFacility::LOG_LOCAL6,
+            Facility::Local7 => SyslogFacility::LOG_LOCAL7,
+        }
+    }
+}
+impl Default for Facility {
+    fn default() -> Self {
+        Facility::User
+    }
+}
+
+/// The configuration of `SyslogLoggerBuilder`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyslogLoggerConfig {
+    /// Log level.
+    #[serde(default)]
+    pub level: Severity,
+
+    /// Syslog facility.
+    #[serde(default)]
+    pub facility: Facility,
+
+    /// Identifier attached to each log record.
+    ///
+    /// The default value is the file name of the current executable.
+    #[serde(default = "default_ident")]
+    pub ident: String,
+
+    /// Asynchronous channel size
+    #[serde(default = "default_channel_size")]
+    pub channel_size: usize,
+
+    /// Per-module log level specification.
+    ///
+    /// See [`ModuleLevels`] for the syntax.
+    ///
+    /// [`ModuleLevels`]: ../types/struct.ModuleLevels.html
+    #[serde(default)]
+    pub module_levels: Option<String>,
+}
+impl Default for SyslogLoggerConfig {
+    fn default() -> Self {
+        SyslogLoggerConfig {
+            level: Severity::default(),
+            facility: Facility::default(),
+            ident: default_ident(),
+            channel_size: default_channel_size(),
+            module_levels: None,
+        }
+    }
+}
+impl Config for SyslogLoggerConfig {
+    type Builder = SyslogLoggerBuilder;
+    fn try_to_builder(&self) -> Result<Self::Builder> {
+        let mut builder = SyslogLoggerBuilder::new();
+        builder.level(self.level);
+        builder.facility(self.facility);
+        builder.ident(self.ident.clone());
+        builder.channel_size(self.channel_size);
+        if let Some(ref spec) = self.module_levels {
+            builder.module_levels(track!(spec.parse())?);
+        }
+        Ok(builder)
+    }
+}
+
+fn default_channel_size() -> usize {
+    1024
+}
@@ -0,0 +1,200 @@
+//! Terminal logger.
+use std::fmt::Debug;
+#[cfg(feature = "json")]
+use std::io;
+use slog::{Drain, FnValue, Logger};
+use slog_async::Async;
+use slog_term::{CompactFormat, FullFormat, TermDecorator};
+#[cfg(feature = "json")]
+use slog_json::Json;
+
+use {Build, Config, Result};
+use misc::{module_and_line, timezone_to_timestamp_fn};
+use types::{Format, ModuleLevelFilter, ModuleLevels, Severity, TimeZone};
+
+/// A logger builder which build loggers that output log records to the terminal.
+///
+/// The resulting logger will work asynchronously (the default channel size is 1024).
+#[derive(Debug)]
+pub struct TerminalLoggerBuilder {
+    format: Format,
+    timezone: TimeZone,
+    destination: Destination,
+    level: Severity,
+    channel_size: usize,
+    module_levels: ModuleLevels,
+}
+impl TerminalLoggerBuilder {
+    /// Makes a new `TerminalLoggerBuilder` instance.
+    pub fn new() -> Self {
+        TerminalLoggerBuilder {
+            format: Format::default(),
+            timezone: TimeZone::default(),
+            destination: Destination::default(),
+            level: Severity::default(),
+            channel_size: 1024,
+            module_levels: ModuleLevels::default(),
+        }
+    }
+
+    /// Sets the format of log records.
+    pub fn format(&mut self, format: Format) -> &mut Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the time zone which this logger will use.
+    pub fn timezone(&mut self, timezone: TimeZone) -> &mut Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Sets the destination to which log records will be written.
+    pub fn destination(&mut self, destination: Destination) -> &mut Self {
+        self.destination = destination;
+        self
+    }
+
+    /// Sets the log level of this logger.
+    pub fn level(&mut self, severity: Severity) -> &mut Self {
+        self.level = severity;
+        self
+    }
+
+    /// Sets the size of the asynchronous channel of this logger.
+    pub fn channel_size(&mut self, channel_size: usize) -> &mut Self {
+        self.channel_size = channel_size;
+        self
+    }
+
+    /// Sets the per-module log level specification of this logger.
+    ///
+    /// See [`ModuleLevels`] for the textual syntax it is parsed from.
+    ///
+    /// [`ModuleLevels`]: ../types/struct.ModuleLevels.html
+    pub fn module_levels(&mut self, module_levels: ModuleLevels) -> &mut Self {
+        self.module_levels = module_levels;
+        self
+    }
+
+    fn build_with_drain<D>(&self, drain: D) -> Logger
+    where
+        D: Drain + Send + 'static,
+        D::Err: Debug,
+    {
+        let drain = Async::new(drain.fuse())
+            .chan_size(self.channel_size)
+            .build()
+            .fuse();
+        let drain = ModuleLevelFilter::new(drain, self.module_levels.clone(), self.level).fuse();
+        Logger::root(drain, o!("module" => FnValue(module_and_line)))
+    }
+}
+impl Build for TerminalLoggerBuilder {
+    fn build(&self) -> Result<Logger> {
+        let timestamp = timezone_to_timestamp_fn(self.timezone);
+        let logger = match self.format {
+            Format::Full => {
+                let decorator = match self.destination {
+                    Destination::Stdout => TermDecorator::new().stdout().build(),
+                    Destination::Stderr => TermDecorator::new().stderr().build(),
+                };
+                let format = FullFormat::new(decorator).use_custom_timestamp(timestamp);
+                self.build_with_drain(format.build())
+            }
+            Format::Compact => {
+                let decorator = match self.destination {
+                    Destination::Stdout => TermDecorator::new().stdout().build(),
+                    Destination::Stderr => TermDecorator::new().stderr().build(),
+                };
+                let format = CompactFormat::new(decorator).use_custom_timestamp(timestamp);
+                self.build_with_drain(format.build())
+            }
+            #[cfg(feature = "json")]
+            Format::Json => match self.destination {
+                Destination::Stdout => {
+                    let format = Json::new(io::stdout())
+                        .add_default_keys()
+                        .set_newlines(true)
+                        .build();
+                    self.build_with_drain(format)
+                }
+                Destination::Stderr => {
+                    let format = Json::new(io::stderr())
+                        .add_default_keys()
+                        .set_newlines(true)
+                        .build();
+                    self.build_with_drain(format)
+                }
+            },
+        };
+        Ok(logger)
+    }
+}
+
+/// The destination to which log records will be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Destination {
+    /// Standard output.
+    Stdout,
+
+    /// Standard error.
+    Stderr,
+}
+impl Default for Destination {
+    fn default() -> Self {
+        Destination::Stdout
+    }
+}
+
+/// The configuration of `TerminalLoggerBuilder`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TerminalLoggerConfig {
+    /// Log level.
+    #[serde(default)]
+    pub level: Severity,
+
+    /// Log record format.
+    #[serde(default)]
+    pub format: Format,
+
+    /// Time Zone.
+    #[serde(default)]
+    pub timezone: TimeZone,
+
+    /// Destination of log records.
+    #[serde(default)]
+    pub destination: Destination,
+
+    /// Asynchronous channel size
+    #[serde(default = "default_channel_size")]
+    pub channel_size: usize,
+
+    /// Per-module log level specification.
+    ///
+    /// See [`ModuleLevels`] for the syntax.
+    ///
+    /// [`ModuleLevels`]: ../types/struct.ModuleLevels.html
+    #[serde(default)]
+    pub module_levels: Option<String>,
+}
+impl Config for TerminalLoggerConfig {
+    type Builder = TerminalLoggerBuilder;
+    fn try_to_builder(&self) -> Result<Self::Builder> {
+        let mut builder = TerminalLoggerBuilder::new();
+        builder.level(self.level);
+        builder.format(self.format);
+        builder.timezone(self.timezone);
+        builder.destination(self.destination);
+        builder.channel_size(self.channel_size);
+        if let Some(ref spec) = self.module_levels {
+            builder.module_levels(track!(spec.parse())?);
+        }
+        Ok(builder)
+    }
+}
+
+fn default_channel_size() -> usize {
+    1024
+}
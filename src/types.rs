@@ -0,0 +1,250 @@
+//! Miscellaneous types.
+use std::str::FromStr;
+use slog::{Drain, Level, LevelFilter, OwnedKVList, Record};
+use trackable::error::ErrorKindExt;
+
+use {Error, ErrorKind, Result};
+
+/// The severity of a log record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Critical,
+    Error,
+    Warning,
+    Info,
+    Debug,
+    Trace,
+}
+impl Severity {
+    /// Converts to `slog::Level`.
+    pub fn as_level(&self) -> Level {
+        match *self {
+            Severity::Critical => Level::Critical,
+            Severity::Error => Level::Error,
+            Severity::Warning => Level::Warning,
+            Severity::Info => Level::Info,
+            Severity::Debug => Level::Debug,
+            Severity::Trace => Level::Trace,
+        }
+    }
+
+    /// Wraps `drain` so that only the records whose level is at least as severe
+    /// as this level are passed through.
+    pub fn set_level_filter<D: Drain>(&self, drain: D) -> LevelFilter<D> {
+        LevelFilter::new(drain, self.as_level())
+    }
+}
+impl Default for Severity {
+    fn default() -> Self {
+        Severity::Info
+    }
+}
+impl FromStr for Severity {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "critical" => Ok(Severity::Critical),
+            "error" => Ok(Severity::Error),
+            "warning" => Ok(Severity::Warning),
+            "info" => Ok(Severity::Info),
+            "debug" => Ok(Severity::Debug),
+            "trace" => Ok(Severity::Trace),
+            _ => Err(ErrorKind::Invalid.cause(format!("unknown severity: {:?}", s)).into()),
+        }
+    }
+}
+
+/// The format of log records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Full format.
+    Full,
+
+    /// Compact format.
+    Compact,
+
+    /// JSON format (one record per line).
+    #[cfg(feature = "json")]
+    Json,
+}
+impl Default for Format {
+    fn default() -> Self {
+        Format::Full
+    }
+}
+
+/// The policy for handling the situation where the destination log file already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IfExists {
+    /// Appends to the existing file.
+    Append,
+
+    /// Truncates the existing file.
+    Truncate,
+
+    /// Fails if the existing file is not empty (an existing empty file is appended to).
+    Fail,
+}
+impl Default for IfExists {
+    fn default() -> Self {
+        IfExists::Append
+    }
+}
+
+/// The time zone which a logger will use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeZone {
+    /// UTC.
+    Utc,
+
+    /// Local time zone.
+    Local,
+}
+impl Default for TimeZone {
+    fn default() -> Self {
+        TimeZone::Utc
+    }
+}
+
+/// A parsed per-module log level specification.
+///
+/// The textual form is a comma-separated list of entries, where each entry is
+/// either a bare `Severity` (which overrides the logger's default level) or a
+/// `module::path=Severity` pair. For example: `"info,myapp::db=debug,myapp::net::sync=error"`.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleLevels {
+    default: Option<Severity>,
+
+    // Sorted by descending path length, so the first matching entry is the
+    // longest (i.e., most specific) matching module path prefix.
+    rules: Vec<(String, Severity)>,
+}
+impl ModuleLevels {
+    /// Returns the log level that should be applied to the module named `module`.
+    pub fn level_for(&self, module: &str, default: Severity) -> Severity {
+        for &(ref prefix, severity) in &self.rules {
+            if module == prefix || module.starts_with(&format!("{}::", prefix)) {
+                return severity;
+            }
+        }
+        self.default.unwrap_or(default)
+    }
+}
+impl FromStr for ModuleLevels {
+    type Err = Error;
+    fn from_str(spec: &str) -> Result<Self> {
+        let mut default = None;
+        let mut rules = Vec::new();
+        for entry in spec.split(',') {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+            if let Some(i) = entry.find('=') {
+                let path = &entry[..i];
+                let severity = track!(entry[i + 1..].parse())?;
+                rules.push((path.to_owned(), severity));
+            } else {
+                default = Some(track!(entry.parse())?);
+            }
+        }
+        rules.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+        Ok(ModuleLevels { default, rules })
+    }
+}
+
+/// A `Drain` that filters log records by the severity configured for their module,
+/// as determined by a `ModuleLevels` specification, falling back to `default_level`
+/// for modules that match none of the specification's entries.
+#[derive(Debug)]
+pub struct ModuleLevelFilter<D> {
+    drain: D,
+    module_levels: ModuleLevels,
+    default_level: Severity,
+}
+impl<D> ModuleLevelFilter<D> {
+    /// Makes a new `ModuleLevelFilter` instance.
+    pub fn new(drain: D, module_levels: ModuleLevels, default_level: Severity) -> Self {
+        ModuleLevelFilter {
+            drain,
+            module_levels,
+            default_level,
+        }
+    }
+}
+impl<D: Drain> Drain for ModuleLevelFilter<D> {
+    type Ok = Option<D::Ok>;
+    type Err = D::Err;
+    fn log(
+        &self,
+        record: &Record,
+        logger_values: &OwnedKVList,
+    ) -> ::std::result::Result<Self::Ok, Self::Err> {
+        let threshold = self
+            .module_levels
+            .level_for(record.module(), self.default_level)
+            .as_level();
+        if record.level().is_at_least(threshold) {
+            Ok(Some(self.drain.log(record, logger_values)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_default_and_module_rules() {
+        let levels: ModuleLevels = "warning,myapp::db=debug,myapp::net::sync=error"
+            .parse()
+            .unwrap();
+        assert_eq!(levels.level_for("myapp", Severity::Info), Severity::Warning);
+        assert_eq!(
+            levels.level_for("myapp::db", Severity::Info),
+            Severity::Debug
+        );
+        assert_eq!(
+            levels.level_for("myapp::db::pool", Severity::Info),
+            Severity::Debug
+        );
+        assert_eq!(
+            levels.level_for("myapp::net::sync", Severity::Info),
+            Severity::Error
+        );
+        assert_eq!(
+            levels.level_for("myapp::net", Severity::Info),
+            Severity::Warning
+        );
+    }
+
+    #[test]
+    fn falls_back_to_callers_default_when_unset() {
+        let levels: ModuleLevels = "myapp::db=debug".parse().unwrap();
+        assert_eq!(levels.level_for("other", Severity::Error), Severity::Error);
+    }
+
+    #[test]
+    fn longest_prefix_wins_regardless_of_entry_order() {
+        let levels: ModuleLevels = "myapp=warning,myapp::db=debug".parse().unwrap();
+        assert_eq!(
+            levels.level_for("myapp::db", Severity::Info),
+            Severity::Debug
+        );
+        assert_eq!(
+            levels.level_for("myapp::other", Severity::Info),
+            Severity::Warning
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_severity() {
+        assert!("myapp=bogus".parse::<ModuleLevels>().is_err());
+    }
+}